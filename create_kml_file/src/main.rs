@@ -25,35 +25,50 @@ fn run() -> anyhow::Result<()> {
         args.data_dir.display()
     );
 
+    let writer = args.format.writer();
+
+    let parcels = discover_parcels(&args.data_dir)
+        .with_context(|| "failed to discover parcels in the data directory")?;
+    ensure!(
+        !parcels.is_empty(),
+        "no parcel data files found in '{}'",
+        args.data_dir.display()
+    );
+
     let mut parcel_bounds = Vec::new();
-    for parcel_num in 1..=2 {
+    for parcel_num in parcels {
         let start = get_starting_location(args.data_dir.as_path(), parcel_num)
             .with_context(|| format!("parcel {parcel_num}: failed to get the starting location"))?;
         let az_dist = get_azimuth_distance(&args.data_dir, parcel_num).with_context(|| {
             format!("parcel {parcel_num}: failed to get the list of azimuth/distance pairs")
         })?;
-        let bounds = calc_bounds(start, az_dist)
+        let bounds = calc_bounds(start, az_dist, args.max_misclosure)
             .with_context(|| format!("parcel {parcel_num}: failed to calculate the boundaries"))?;
-        write_parcel_points_kml(parcel_num, &bounds)
-            .with_context(|| "parcel {parcel_num}: failed to write parcel survey points KML")?;
+        info!("parcel {parcel_num}: {}", calc_metrics(&bounds));
+        writer
+            .write_parcel_points(parcel_num, &bounds)
+            .with_context(|| format!("parcel {parcel_num}: failed to write parcel survey points"))?;
         parcel_bounds.push(bounds);
     }
 
-    write_survey_outline_kml(&parcel_bounds)
-        .with_context(|| "failed to write the survey outline KML")?;
+    writer
+        .write_survey_outline(&parcel_bounds)
+        .with_context(|| "failed to write the survey outline")?;
 
     Ok(())
 }
 
 struct CmdlineArgs {
     data_dir: PathBuf,
+    format: Format,
+    max_misclosure: f64,
 }
 
 fn parse_cmdline() -> CmdlineArgs {
     let cmd = Command::new("create_kml_files")
         .author("Russ Goetz, russgoetz@gmail.com")
         .version("1.0.0")
-        .about("Generates a single survey outline KML file containing both parcels and a survey points KML file for each parcel.")
+        .about("Generates a single survey outline file containing every parcel and a survey points file for each parcel.")
         .arg(
             Arg::new("data_dir")
                 .long("data-dir")
@@ -61,11 +76,29 @@ fn parse_cmdline() -> CmdlineArgs {
                 .value_name("DATA-DIR")
                 .value_parser(PathBufValueParser::new())
                 .help("The full path to the directory containing the start and azimuth/distance data files for each parcel.")
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .value_parser(["kml", "geojson", "wkt", "csv"])
+                .default_value("kml")
+                .help("The output format for the generated survey files.")
+        )
+        .arg(
+            Arg::new("max_misclosure")
+                .long("max-misclosure")
+                .value_name("METERS")
+                .value_parser(clap::value_parser!(f64))
+                .default_value("1.0")
+                .help("The largest traverse linear misclosure, in meters, to accept and adjust before erroring out.")
         );
 
     let m = cmd.get_matches();
     CmdlineArgs {
         data_dir: m.get_one::<PathBuf>("data_dir").unwrap().clone(),
+        format: m.get_one::<String>("format").unwrap().parse().unwrap(),
+        max_misclosure: *m.get_one::<f64>("max_misclosure").unwrap(),
     }
 }
 
@@ -138,6 +171,40 @@ impl<'a> Iterator for SplitWhitespaceN<'a> {
     }
 }
 
+/// Scans `data_dir` for every parcel that has both a start and a
+/// bearing/distance file, returning the discovered parcel numbers in order so
+/// surveys with any number of parcels work without code changes.
+fn discover_parcels(data_dir: &Path) -> anyhow::Result<Vec<i32>> {
+    use std::collections::HashSet;
+
+    let mut bearing = HashSet::new();
+    let mut start = HashSet::new();
+    for entry in fs::read_dir(data_dir)
+        .context(format!("failed to read directory '{}'", data_dir.display()))?
+    {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        let Some(rest) = name.strip_prefix("parcel") else {
+            continue;
+        };
+        if let Some(num) = rest.strip_suffix("_bearing_distance.txt") {
+            if let Ok(num) = num.parse::<i32>() {
+                bearing.insert(num);
+            }
+        } else if let Some(num) = rest.strip_suffix("_start_lat_lon.txt") {
+            if let Ok(num) = num.parse::<i32>() {
+                start.insert(num);
+            }
+        }
+    }
+
+    let mut parcels: Vec<i32> = bearing.intersection(&start).copied().collect();
+    parcels.sort();
+    trace!("discovered parcels: {:?}", parcels);
+    Ok(parcels)
+}
+
 fn get_starting_location(data_dir: &Path, parcel_num: i32) -> anyhow::Result<NamedPoint> {
     let filename = String::from("parcel") + parcel_num.to_string().as_str() + "_start_lat_lon.txt";
     let mut path = data_dir.to_path_buf();
@@ -238,48 +305,92 @@ fn get_azimuth_distance(
 
     let mut az_dist = Vec::new();
     for line in reader.lines() {
-        // Example line: S 78 03 13 E 171.48 Corner 18
+        // Example lines, all accepted:
+        //   S 78 03 13 E 171.48 Corner 18
+        //   S 78°03'13" E 171.48 Corner 18
+        //   S 78.0536 E 171.48 Corner 18
+        //   AZ 269.329 171.48 Corner 18
         let line = line.context(format!("failed to read '{}'", path.display().to_string()))?;
         let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
 
-        let Some((face, deg, min, sec, turn, dist_ft, name)) =
-            split_whitespace_n(line, 7).collect_tuple()
-        else {
-            bail!("failed to split bearing/distance line into parts");
-        };
+        // Fold the degree/minute/second symbols into separators so every
+        // bearing form tokenizes the same way.
+        let normalized = line.replace(['°', '′', '″', '\'', '"'], " ");
+        let tokens: Vec<&str> = normalized.split_whitespace().collect();
 
-        let face = face
-            .parse::<FaceDir>()
-            .context(format!("invalid face direction '{}'", face))?;
-        let deg: f64 = deg
-            .parse::<f64>()
-            .context(format!("invalid degrees '{}'", deg))?;
-        let min: f64 = min
-            .parse::<f64>()
-            .context(format!("invalid minutes '{}'", min))?;
-        let sec = sec
-            .parse::<f64>()
-            .context(format!("invalid seconds '{}'", sec))?;
-        let turn = turn
-            .parse::<TurnDir>()
-            .context(format!("invalid turn direction '{}'", turn))?;
-        let dist_ft: f64 = dist_ft
+        let (az, consumed) = parse_bearing(&tokens)
+            .context(format!("failed to parse bearing from line '{}'", line))?;
+
+        let dist_ft = tokens
+            .get(consumed)
+            .context("missing distance")?
             .parse::<f64>()
-            .context(format!("invalid distance '{}'", dist_ft))?;
-        trace!("{face} {deg}° {min}′ {sec}″ {turn}, distance = {dist_ft} ft, name = {name}");
+            .context("invalid distance")?;
+        // Every remaining token is part of the point name, so names may contain spaces.
+        let name = tokens[consumed + 1..].join(" ");
 
-        // Convert bearing as <face> <D:M:S> <turn> to azimuth in degrees decimal.
-        let az = bearing_to_azimuth(face, deg, min, sec, turn);
         // Convert distance from feet to meters.
         let dist = dist_ft * 0.3048;
-        trace!("\taz = {az}°, dist = {dist} m");
+        trace!("az = {az}°, dist = {dist} m, name = {name}");
 
-        az_dist.push((az, dist, name.to_string()));
+        az_dist.push((az, dist, name));
     }
 
     Ok(az_dist)
 }
 
+/// Parses the leading bearing of a tokenized bearing/distance line, returning
+/// the azimuth in decimal degrees and the number of tokens it consumed. Accepts
+/// a quadrant bearing with degrees/minutes/seconds, a quadrant bearing in
+/// decimal degrees, and a bare `AZ <azimuth>` form.
+fn parse_bearing(tokens: &[&str]) -> anyhow::Result<(f64, usize)> {
+    let first = tokens.first().context("empty bearing")?;
+
+    // Bare azimuth form: AZ 269.329
+    if first.eq_ignore_ascii_case("AZ") {
+        let az = tokens
+            .get(1)
+            .context("missing azimuth")?
+            .parse::<f64>()
+            .context(format!("invalid azimuth '{}'", tokens.get(1).unwrap_or(&"")))?;
+        return Ok((az, 2));
+    }
+
+    // Quadrant bearing: <face> <angle...> <turn>, where the angle is a single
+    // decimal degree value, degrees/minutes, or degrees/minutes/seconds.
+    let face = first
+        .parse::<FaceDir>()
+        .context(format!("invalid face direction '{}'", first))?;
+
+    let mut angle = Vec::new();
+    let mut idx = 1;
+    while idx < tokens.len() && tokens[idx].parse::<TurnDir>().is_err() {
+        let part = tokens[idx]
+            .parse::<f64>()
+            .context(format!("invalid angle component '{}'", tokens[idx]))?;
+        angle.push(part);
+        idx += 1;
+    }
+    let turn = tokens
+        .get(idx)
+        .context("missing turn direction")?
+        .parse::<TurnDir>()
+        .context(format!("invalid turn direction '{}'", tokens[idx]))?;
+
+    let (deg, min, sec) = match angle.as_slice() {
+        [deg] => (*deg, 0.0, 0.0),
+        [deg, min] => (*deg, *min, 0.0),
+        [deg, min, sec] => (*deg, *min, *sec),
+        _ => bail!("expected one to three angle components"),
+    };
+
+    let az = bearing_to_azimuth(face, deg, min, sec, turn);
+    Ok((az, idx + 1))
+}
+
 fn bearing_to_azimuth(face: FaceDir, deg: f64, min: f64, sec: f64, turn: TurnDir) -> f64 {
     let angle = deg + min / 60.0 + sec / 3600.0;
 
@@ -300,26 +411,58 @@ fn bearing_to_azimuth(face: FaceDir, deg: f64, min: f64, sec: f64, turn: TurnDir
 fn calc_bounds(
     start: NamedPoint,
     az_dist: Vec<(f64, f64, String)>,
+    max_misclosure: f64,
 ) -> anyhow::Result<Vec<NamedPoint>> {
     use geo::algorithm::geodesic_destination::GeodesicDestination;
 
-    let mut bounds = vec![start];
-    for (idx, (az, dist, name)) in az_dist.into_iter().enumerate() {
-        let point = bounds[idx].point.geodesic_destination(az, dist);
-        let named_point = NamedPoint::new(point, name);
-        bounds.push(named_point);
-    }
-    let first = bounds.first().unwrap();
-    let last = bounds.last().unwrap();
-    ensure!(
-        (last.x() - first.x()).abs() < 0.000001,
-        "x coordinate of last point doesn't match the first point"
-    );
+    // Real deed traverses almost never close exactly, so rather than rejecting
+    // any residual we distribute it with the compass (Bowditch) rule: work in a
+    // local planar ENU frame, find the misclosure in departure/latitude, and
+    // push corrections back into each leg in proportion to its length.
+    let total_dist: f64 = az_dist.iter().map(|(_, dist, _)| dist).sum();
+    ensure!(total_dist > 0.0, "traverse has zero total length");
+
+    // Raw departure (ΔE) and latitude (ΔN) for every leg.
+    let legs: Vec<(f64, f64)> = az_dist
+        .iter()
+        .map(|(az, dist, _)| {
+            let az = az.to_radians();
+            (dist * az.sin(), dist * az.cos())
+        })
+        .collect();
+    let err_e: f64 = legs.iter().map(|(de, _)| de).sum();
+    let err_n: f64 = legs.iter().map(|(_, dn)| dn).sum();
+
+    let misclosure = (err_e * err_e + err_n * err_n).sqrt();
+    let precision = if misclosure > 0.0 {
+        total_dist / misclosure
+    } else {
+        f64::INFINITY
+    };
+    info!("traverse linear misclosure = {misclosure:.4} m, precision = 1:{precision:.0}");
     ensure!(
-        (last.y() - first.y()).abs() < 0.000001,
-        "y coordinate of last point doesn't match the first point"
+        misclosure <= max_misclosure,
+        "traverse misclosure {misclosure:.4} m exceeds the maximum of {max_misclosure:.4} m"
     );
-    // The last point is effectively a copy of the first point, so it can safely be removed.
+
+    // Rebuild the ring from the adjusted legs. Each corrected departure/latitude
+    // is turned back into an azimuth/distance and applied as a geodesic
+    // destination so the rest of the geo/KML pipeline is unchanged.
+    let mut bounds = vec![start];
+    for (idx, (_, dist, name)) in az_dist.into_iter().enumerate() {
+        let (de, dn) = legs[idx];
+        let de = de - err_e * (dist / total_dist);
+        let dn = dn - err_n * (dist / total_dist);
+        let mut adj_az = de.atan2(dn).to_degrees();
+        if adj_az < 0.0 {
+            adj_az += 360.0;
+        }
+        let adj_dist = (de * de + dn * dn).sqrt();
+        let point = bounds[idx].point.geodesic_destination(adj_az, adj_dist);
+        bounds.push(NamedPoint::new(point, name));
+    }
+    // After adjustment the closing leg returns to the start, so the last point
+    // is effectively a copy of the first point and can safely be removed.
     bounds.pop();
 
     trace!("{} boundary points", bounds.len());
@@ -335,70 +478,515 @@ fn calc_bounds(
     Ok(bounds)
 }
 
-fn write_survey_outline_kml(parcel_bounds: &Vec<Vec<NamedPoint>>) -> anyhow::Result<()> {
-    use std::io::Write;
+/// Geodesic size of a parcel, in the survey's customary units.
+struct ParcelMetrics {
+    area_sqft: f64,
+    area_acres: f64,
+    perimeter_ft: f64,
+}
 
-    let file = File::create("survey_outline.kml")?;
-    let mut writer = BufWriter::new(file);
+impl fmt::Display for ParcelMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Area: {:.2} acres ({:.0} sq ft), Perimeter: {:.2} ft",
+            self.area_acres, self.area_sqft, self.perimeter_ft
+        )
+    }
+}
+
+/// Computes a parcel's geodesic area and perimeter from its closed boundary
+/// ring. Areas come from geo's ellipsoidal algorithm rather than a planar
+/// shoelace so they stay accurate over the same ellipsoid the ring was built
+/// on, then are converted from SI to the survey feet / acres used in the data.
+fn calc_metrics(bounds: &[NamedPoint]) -> ParcelMetrics {
+    use geo::algorithm::geodesic_area::GeodesicArea;
+    use geo::algorithm::geodesic_length::GeodesicLength;
+
+    // Close the ring by repeating the start point so both the area and the
+    // perimeter account for the final leg back to the beginning.
+    let mut coords: Vec<geo::Coord> = bounds.iter().map(|b| b.point.0).collect();
+    if let Some(first) = bounds.first() {
+        coords.push(first.point.0);
+    }
+    let ring = geo::LineString::new(coords);
+
+    // 1 m = 1 / 0.3048 ft, so 1 m² = (1 / 0.3048)² sq ft, and 1 acre = 43560 sq ft.
+    const SQ_M_TO_SQ_FT: f64 = 1.0 / (0.3048 * 0.3048);
+    const SQ_FT_PER_ACRE: f64 = 43560.0;
+
+    let area_sqm = geo::Polygon::new(ring.clone(), vec![]).geodesic_area_unsigned();
+    let area_sqft = area_sqm * SQ_M_TO_SQ_FT;
+    let perimeter_ft = ring.geodesic_length() / 0.3048;
+
+    ParcelMetrics {
+        area_sqft,
+        area_acres: area_sqft / SQ_FT_PER_ACRE,
+        perimeter_ft,
+    }
+}
+
+/// A square cell of the polylabel quadtree search, scored by an upper bound on
+/// the pole-of-inaccessibility distance reachable anywhere inside it.
+struct Cell {
+    center: geo::Point,
+    /// Half the cell's side length.
+    h: f64,
+    /// Signed distance from the cell center to the polygon boundary (positive
+    /// inside the polygon).
+    d: f64,
+    /// Upper bound: the best distance any point in the cell could achieve.
+    max: f64,
+}
+
+impl Cell {
+    fn new(x: f64, y: f64, h: f64, poly: &geo::Polygon) -> Self {
+        let center = geo::Point::new(x, y);
+        let d = signed_distance(poly, center);
+        Self {
+            center,
+            h,
+            d,
+            max: d + h * std::f64::consts::SQRT_2,
+        }
+    }
+}
+
+impl PartialEq for Cell {
+    fn eq(&self, other: &Self) -> bool {
+        self.max == other.max
+    }
+}
+
+impl Eq for Cell {}
+
+impl PartialOrd for Cell {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cell {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.max.total_cmp(&other.max)
+    }
+}
 
-    writeln!(writer, "{}", get_leading_kml("Survey Outline")?)?;
+/// Signed distance from `p` to the polygon boundary, positive when `p` lies
+/// inside the polygon and negative when it lies outside.
+fn signed_distance(poly: &geo::Polygon, p: geo::Point) -> f64 {
+    use geo::algorithm::contains::Contains;
+    use geo::algorithm::euclidean_distance::EuclideanDistance;
+
+    let dist = p.euclidean_distance(poly.exterior());
+    if poly.contains(&p) {
+        dist
+    } else {
+        -dist
+    }
+}
 
-    for (idx, bounds) in parcel_bounds.iter().enumerate() {
-        writeln!(writer, "\t<Placemark>")?;
-        let parcel_name = format!("<name>Parcel {}</name>", idx + 1);
-        writeln!(writer, "\t\t{}", parcel_name)?;
-        writeln!(writer, "\t\t<styleUrl>#icon-1739-0288D1-nodesc</styleUrl>")?;
-        writeln!(writer, "\t\t<Polygon>")?;
-        writeln!(writer, "\t\t\t<outerBoundaryIs>")?;
-        writeln!(writer, "\t\t\t\t<LinearRing>")?;
-        writeln!(writer, "\t\t\t\t\t<coordinates>")?;
+/// Computes the pole of inaccessibility — the interior point farthest from any
+/// edge — of a parcel polygon using the polylabel quadtree search. The grid of
+/// cells covering the bounding box is refined with a max-priority queue,
+/// repeatedly subdividing the most promising cell until its upper bound can no
+/// longer beat the best center found by more than a small epsilon.
+fn pole_of_inaccessibility(bounds: &[NamedPoint]) -> geo::Point {
+    use geo::algorithm::centroid::Centroid;
+    use std::collections::BinaryHeap;
+
+    let mut coords: Vec<geo::Coord> = bounds.iter().map(|b| b.point.0).collect();
+    if let Some(first) = bounds.first() {
+        coords.push(first.point.0);
+    }
+    let poly = geo::Polygon::new(geo::LineString::new(coords), vec![]);
+
+    let (min_x, max_x, min_y, max_y) = bounds.iter().fold(
+        (f64::MAX, f64::MIN, f64::MAX, f64::MIN),
+        |(min_x, max_x, min_y, max_y), b| {
+            (
+                min_x.min(b.x()),
+                max_x.max(b.x()),
+                min_y.min(b.y()),
+                max_y.max(b.y()),
+            )
+        },
+    );
+
+    let cell_size = (max_x - min_x).min(max_y - min_y);
+    // A degenerate polygon has no interior; fall back to the centroid.
+    if cell_size <= 0.0 {
+        return poly.centroid().unwrap_or_else(|| geo::Point::new(min_x, min_y));
+    }
+    let h = cell_size / 2.0;
+    let precision = cell_size / 1000.0;
+
+    // Seed the queue with a grid of square cells covering the bounding box.
+    let mut queue = BinaryHeap::new();
+    let mut x = min_x;
+    while x < max_x {
+        let mut y = min_y;
+        while y < max_y {
+            queue.push(Cell::new(x + h, y + h, h, &poly));
+            y += cell_size;
+        }
+        x += cell_size;
+    }
+
+    // Start from the centroid and keep the best-scoring cell center seen so far.
+    let mut best = poly
+        .centroid()
+        .map(|c| Cell::new(c.x(), c.y(), 0.0, &poly))
+        .unwrap_or_else(|| Cell::new(min_x + h, min_y + h, 0.0, &poly));
+
+    while let Some(cell) = queue.pop() {
+        if cell.d > best.d {
+            best = Cell::new(cell.center.x(), cell.center.y(), 0.0, &poly);
+        }
+        // Can't contain a better point than we already have, within epsilon.
+        if cell.max - best.d <= precision {
+            continue;
+        }
+        // Subdivide into four quadrant cells.
+        let h = cell.h / 2.0;
+        queue.push(Cell::new(cell.center.x() - h, cell.center.y() - h, h, &poly));
+        queue.push(Cell::new(cell.center.x() + h, cell.center.y() - h, h, &poly));
+        queue.push(Cell::new(cell.center.x() - h, cell.center.y() + h, h, &poly));
+        queue.push(Cell::new(cell.center.x() + h, cell.center.y() + h, h, &poly));
+    }
+
+    best.center
+}
+
+/// The output formats understood by the `--format` flag.
+enum Format {
+    Kml,
+    GeoJson,
+    Wkt,
+    Csv,
+}
 
-        let coords = bounds
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "kml" => Ok(Format::Kml),
+            "geojson" => Ok(Format::GeoJson),
+            "wkt" => Ok(Format::Wkt),
+            "csv" => Ok(Format::Csv),
+            _ => bail!("invalid output format"),
+        }
+    }
+}
+
+impl Format {
+    fn writer(&self) -> Box<dyn ParcelWriter> {
+        match self {
+            Format::Kml => Box::new(KmlWriter),
+            Format::GeoJson => Box::new(GeoJsonWriter),
+            Format::Wkt => Box::new(WktWriter),
+            Format::Csv => Box::new(CsvWriter),
+        }
+    }
+}
+
+/// Emits the computed parcel boundaries and survey points in a particular file
+/// format. Each implementation owns the full on-disk layout for its format.
+trait ParcelWriter {
+    /// Writes the combined survey outline, one closed polygon per parcel.
+    fn write_survey_outline(&self, parcel_bounds: &[Vec<NamedPoint>]) -> anyhow::Result<()>;
+
+    /// Writes the survey points for a single parcel.
+    fn write_parcel_points(&self, parcel_num: i32, bounds: &[NamedPoint]) -> anyhow::Result<()>;
+}
+
+struct KmlWriter;
+
+impl ParcelWriter for KmlWriter {
+    fn write_survey_outline(&self, parcel_bounds: &[Vec<NamedPoint>]) -> anyhow::Result<()> {
+        use std::io::Write;
+
+        let file = File::create("survey_outline.kml")?;
+        let mut writer = BufWriter::new(file);
+
+        writeln!(writer, "{}", get_leading_kml("Survey Outline")?)?;
+
+        for (idx, bounds) in parcel_bounds.iter().enumerate() {
+            writeln!(writer, "\t<Placemark>")?;
+            let parcel_name = format!("<name>Parcel {}</name>", idx + 1);
+            writeln!(writer, "\t\t{}", parcel_name)?;
+            let metrics = calc_metrics(bounds);
+            writeln!(
+                writer,
+                "\t\t<description>Area: {:.2} acres, Perimeter: {:.0} ft</description>",
+                metrics.area_acres, metrics.perimeter_ft
+            )?;
+            writeln!(writer, "\t\t<styleUrl>#icon-1739-0288D1-nodesc</styleUrl>")?;
+            writeln!(writer, "\t\t<Polygon>")?;
+            writeln!(writer, "\t\t\t<outerBoundaryIs>")?;
+            writeln!(writer, "\t\t\t\t<LinearRing>")?;
+            writeln!(writer, "\t\t\t\t\t<coordinates>")?;
+
+            let coords = bounds
+                .iter()
+                .map(|b| format!("{},{}", b.x(), b.y()))
+                .collect::<Vec<String>>()
+                .join("\n\t\t\t\t\t\t");
+            writeln!(writer, "\t\t\t\t\t\t{coords}")?;
+
+            writeln!(writer, "\t\t\t\t\t</coordinates>")?;
+            writeln!(writer, "\t\t\t\t</LinearRing>")?;
+            writeln!(writer, "\t\t\t</outerBoundaryIs>")?;
+            writeln!(writer, "\t\t</Polygon>")?;
+            writeln!(writer, "\t</Placemark>")?;
+
+            // Anchor the parcel label at the pole of inaccessibility so Google
+            // Earth keeps multi-word names centered rather than at a vertex.
+            let label = pole_of_inaccessibility(bounds);
+            writeln!(writer, "\t<Placemark>")?;
+            writeln!(writer, "\t\t<name>Parcel {}</name>", idx + 1)?;
+            writeln!(writer, "\t\t<styleUrl>#label-nodesc</styleUrl>")?;
+            writeln!(writer, "\t\t<Point>")?;
+            writeln!(writer, "\t\t\t<coordinates>")?;
+            writeln!(writer, "\t\t\t\t{},{}", label.x(), label.y())?;
+            writeln!(writer, "\t\t\t</coordinates>")?;
+            writeln!(writer, "\t\t</Point>")?;
+            writeln!(writer, "\t</Placemark>")?;
+        }
+
+        writeln!(writer, "{}", get_trailing_kml()?)?;
+
+        Ok(())
+    }
+
+    fn write_parcel_points(&self, parcel_num: i32, bounds: &[NamedPoint]) -> anyhow::Result<()> {
+        use std::io::Write;
+
+        let file = File::create(format!("parcel{}_survey_points.kml", parcel_num))?;
+        let mut writer = BufWriter::new(file);
+
+        writeln!(
+            writer,
+            "{}",
+            get_leading_kml(format!("Parcel {} Survey Points", parcel_num).as_str())?
+        )?;
+
+        for bound in bounds.iter() {
+            writeln!(writer, "\t<Placemark>")?;
+            writeln!(writer, "\t\t<name>{}</name>", bound.name)?;
+            writeln!(writer, "\t\t<styleUrl>#icon-1739-0288D1-nodesc</styleUrl>")?;
+            writeln!(writer, "\t\t<Point>")?;
+            writeln!(writer, "\t\t\t<coordinates>")?;
+            writeln!(writer, "{}", format!("\t\t\t\t{},{}", bound.x(), bound.y()))?;
+            writeln!(writer, "\t\t\t</coordinates>")?;
+            writeln!(writer, "\t\t</Point>")?;
+            writeln!(writer, "\t</Placemark>")?;
+        }
+
+        writeln!(writer, "{}", get_trailing_kml()?)?;
+
+        Ok(())
+    }
+}
+
+struct GeoJsonWriter;
+
+impl GeoJsonWriter {
+    /// Formats a closed ring of boundary points as a GeoJSON coordinate array,
+    /// repeating the first vertex at the end as the spec requires.
+    fn ring_coords(bounds: &[NamedPoint]) -> String {
+        let mut coords = bounds
             .iter()
-            .map(|b| format!("{},{}", b.x(), b.y()))
-            .collect::<Vec<String>>()
-            .join("\n\t\t\t\t\t\t");
-        writeln!(writer, "\t\t\t\t\t\t{coords}")?;
+            .map(|b| format!("[{}, {}]", b.x(), b.y()))
+            .collect::<Vec<String>>();
+        if let Some(first) = bounds.first() {
+            coords.push(format!("[{}, {}]", first.x(), first.y()));
+        }
+        coords.join(", ")
+    }
+}
 
-        writeln!(writer, "\t\t\t\t\t</coordinates>")?;
-        writeln!(writer, "\t\t\t\t</LinearRing>")?;
-        writeln!(writer, "\t\t\t</outerBoundaryIs>")?;
-        writeln!(writer, "\t\t</Polygon>")?;
-        writeln!(writer, "\t</Placemark>")?;
+/// Escapes the contents of a JSON string literal so arbitrary point names stay
+/// valid JSON: backslashes, double quotes, and control characters.
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
     }
+    out
+}
 
-    writeln!(writer, "{}", get_trailing_kml()?)?;
+/// Escapes a CSV field per RFC 4180: if it contains a comma, double quote, or
+/// newline, wrap it in double quotes and double any embedded quotes.
+fn csv_escape(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
 
-    Ok(())
+impl ParcelWriter for GeoJsonWriter {
+    fn write_survey_outline(&self, parcel_bounds: &[Vec<NamedPoint>]) -> anyhow::Result<()> {
+        use std::io::Write;
+
+        let file = File::create("survey_outline.geojson")?;
+        let mut writer = BufWriter::new(file);
+
+        let mut features = Vec::new();
+        for (idx, bounds) in parcel_bounds.iter().enumerate() {
+            features.push(format!(
+                r#"{{"type": "Feature", "properties": {{"name": "Parcel {}"}}, "geometry": {{"type": "Polygon", "coordinates": [[{}]]}}}}"#,
+                idx + 1,
+                Self::ring_coords(bounds)
+            ));
+            for b in bounds {
+                features.push(format!(
+                    r#"{{"type": "Feature", "properties": {{"name": "{}"}}, "geometry": {{"type": "Point", "coordinates": [{}, {}]}}}}"#,
+                    json_escape(&b.name),
+                    b.x(),
+                    b.y()
+                ));
+            }
+        }
+
+        writeln!(
+            writer,
+            r#"{{"type": "FeatureCollection", "features": [{}]}}"#,
+            features.join(", ")
+        )?;
+
+        Ok(())
+    }
+
+    fn write_parcel_points(&self, parcel_num: i32, bounds: &[NamedPoint]) -> anyhow::Result<()> {
+        use std::io::Write;
+
+        let file = File::create(format!("parcel{}_survey_points.geojson", parcel_num))?;
+        let mut writer = BufWriter::new(file);
+
+        let features = bounds
+            .iter()
+            .map(|b| {
+                format!(
+                    r#"{{"type": "Feature", "properties": {{"name": "{}"}}, "geometry": {{"type": "Point", "coordinates": [{}, {}]}}}}"#,
+                    json_escape(&b.name),
+                    b.x(),
+                    b.y()
+                )
+            })
+            .collect::<Vec<String>>();
+
+        writeln!(
+            writer,
+            r#"{{"type": "FeatureCollection", "features": [{}]}}"#,
+            features.join(", ")
+        )?;
+
+        Ok(())
+    }
 }
 
-fn write_parcel_points_kml(parcel_num: i32, bounds: &Vec<NamedPoint>) -> anyhow::Result<()> {
-    use std::io::Write;
+struct WktWriter;
 
-    let file = File::create(format!("parcel{}_survey_points.kml", parcel_num))?;
-    let mut writer = BufWriter::new(file);
+impl WktWriter {
+    /// Formats a closed ring of boundary points as a WKT `POLYGON`, repeating
+    /// the first vertex at the end to close the ring.
+    fn polygon(bounds: &[NamedPoint]) -> String {
+        let mut coords = bounds
+            .iter()
+            .map(|b| format!("{} {}", b.x(), b.y()))
+            .collect::<Vec<String>>();
+        if let Some(first) = bounds.first() {
+            coords.push(format!("{} {}", first.x(), first.y()));
+        }
+        format!("POLYGON(({}))", coords.join(", "))
+    }
+}
 
-    writeln!(
-        writer,
-        "{}",
-        get_leading_kml(format!("Parcel {} Survey Points", parcel_num).as_str())?
-    )?;
+impl ParcelWriter for WktWriter {
+    fn write_survey_outline(&self, parcel_bounds: &[Vec<NamedPoint>]) -> anyhow::Result<()> {
+        use std::io::Write;
+
+        let file = File::create("survey_outline.wkt")?;
+        let mut writer = BufWriter::new(file);
 
-    for bound in bounds.into_iter() {
-        writeln!(writer, "\t<Placemark>")?;
-        writeln!(writer, "\t\t<name>{}</name>", bound.name)?;
-        writeln!(writer, "\t\t<styleUrl>#icon-1739-0288D1-nodesc</styleUrl>")?;
-        writeln!(writer, "\t\t<Point>")?;
-        writeln!(writer, "\t\t\t<coordinates>")?;
-        writeln!(writer, "{}", format!("\t\t\t\t{},{}", bound.x(), bound.y()))?;
-        writeln!(writer, "\t\t\t</coordinates>")?;
-        writeln!(writer, "\t\t</Point>")?;
-        writeln!(writer, "\t</Placemark>")?;
+        for bounds in parcel_bounds {
+            writeln!(writer, "{}", Self::polygon(bounds))?;
+        }
+
+        Ok(())
     }
 
-    writeln!(writer, "{}", get_trailing_kml()?)?;
+    fn write_parcel_points(&self, parcel_num: i32, bounds: &[NamedPoint]) -> anyhow::Result<()> {
+        use std::io::Write;
 
-    Ok(())
+        let file = File::create(format!("parcel{}_survey_points.wkt", parcel_num))?;
+        let mut writer = BufWriter::new(file);
+
+        for b in bounds {
+            writeln!(writer, "POINT({} {})", b.x(), b.y())?;
+        }
+
+        Ok(())
+    }
+}
+
+struct CsvWriter;
+
+impl ParcelWriter for CsvWriter {
+    fn write_survey_outline(&self, parcel_bounds: &[Vec<NamedPoint>]) -> anyhow::Result<()> {
+        use std::io::Write;
+
+        let file = File::create("survey_outline.csv")?;
+        let mut writer = BufWriter::new(file);
+
+        writeln!(writer, "parcel,name,lon,lat")?;
+        for (idx, bounds) in parcel_bounds.iter().enumerate() {
+            for b in bounds {
+                writeln!(
+                    writer,
+                    "{},{},{},{}",
+                    idx + 1,
+                    csv_escape(&b.name),
+                    b.x(),
+                    b.y()
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_parcel_points(&self, parcel_num: i32, bounds: &[NamedPoint]) -> anyhow::Result<()> {
+        use std::io::Write;
+
+        let file = File::create(format!("parcel{}_survey_points.csv", parcel_num))?;
+        let mut writer = BufWriter::new(file);
+
+        writeln!(writer, "parcel,name,lon,lat")?;
+        for b in bounds {
+            writeln!(
+                writer,
+                "{},{},{},{}",
+                parcel_num,
+                csv_escape(&b.name),
+                b.x(),
+                b.y()
+            )?;
+        }
+
+        Ok(())
+    }
 }
 
 fn get_leading_kml(name: &str) -> anyhow::Result<String> {
@@ -456,7 +1044,7 @@ fn get_style_kml() -> String {
             <scale>0</scale>
         </LabelStyle>
         <BalloonStyle>
-            <text><![CDATA[<h3>$[name]</h3>]]></text>
+            <text><![CDATA[<h3>$[name]</h3>$[description]]]></text>
         </BalloonStyle>
 		<LineStyle>
 			<color>ff0000ff</color>
@@ -480,7 +1068,7 @@ fn get_style_kml() -> String {
             <scale>1</scale>
         </LabelStyle>
         <BalloonStyle>
-            <text><![CDATA[<h3>$[name]</h3>]]></text>
+            <text><![CDATA[<h3>$[name]</h3>$[description]]]></text>
         </BalloonStyle>
 		<LineStyle>
 			<color>ff0000ff</color>
@@ -491,6 +1079,14 @@ fn get_style_kml() -> String {
 			<fill>1</fill>
             <color>200000ff</color>
 		</PolyStyle>
+	</Style>
+	<Style id="label-nodesc">
+        <IconStyle>
+            <scale>0</scale>
+        </IconStyle>
+        <LabelStyle>
+            <scale>1</scale>
+        </LabelStyle>
 	</Style>"#
         .to_string()
 }
@@ -519,4 +1115,35 @@ mod test {
         assert_eq!(p2, "now");
         assert_eq!(p3, "brown cow");
     }
+
+    #[test]
+    fn parse_bearing_forms() {
+        let dms = vec!["S", "78", "03", "13", "E", "171.48", "Corner", "18"];
+        let (az_dms, consumed) = parse_bearing(&dms).unwrap();
+        assert_eq!(consumed, 5);
+
+        let decimal = vec!["S", "78.0536", "E", "171.48"];
+        let (az_decimal, consumed) = parse_bearing(&decimal).unwrap();
+        assert_eq!(consumed, 3);
+        assert!((az_dms - az_decimal).abs() < 1e-3);
+
+        let az = vec!["AZ", "269.329", "171.48"];
+        let (az_bare, consumed) = parse_bearing(&az).unwrap();
+        assert_eq!(consumed, 2);
+        assert!((az_bare - 269.329).abs() < 1e-9);
+    }
+
+    #[test]
+    fn wkt_polygon_closes_ring() {
+        let bounds = vec![
+            NamedPoint::new(geo::Point::new(-84.1, 39.6), "A".to_string()),
+            NamedPoint::new(geo::Point::new(-84.2, 39.7), "B".to_string()),
+            NamedPoint::new(geo::Point::new(-84.3, 39.6), "C".to_string()),
+        ];
+        let wkt = WktWriter::polygon(&bounds);
+        assert_eq!(
+            wkt,
+            "POLYGON((-84.1 39.6, -84.2 39.7, -84.3 39.6, -84.1 39.6))"
+        );
+    }
 }